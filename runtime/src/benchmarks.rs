@@ -30,6 +30,9 @@ frame_benchmarking::define_benchmarks!(
 	[pallet_balances, Balances]
 	[pallet_timestamp, Timestamp]
 	[pallet_sudo, Sudo]
+	[pallet_utility, Utility]
+	[pallet_preimage, Preimage]
+	[pallet_scheduler, Scheduler]
 	[pallet_contracts, Contracts]
 	[pallet_template, Template]
 );
@@ -26,16 +26,21 @@
 // Substrate and Polkadot dependencies
 use frame_support::{
 	derive_impl, parameter_types,
-	traits::{ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, VariantCountOf, Nothing },
+	traits::{
+		fungible::HoldConsideration, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Contains,
+		EqualPrivilegeOnly, LinearStoragePrice, VariantCountOf,
+	},
 	weights::{
 		constants::{RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND},
 		IdentityFee, Weight,
 	},
 };
-use frame_system::limits::{BlockLength, BlockWeights};
+use frame_system::{
+	limits::{BlockLength, BlockWeights},
+	EnsureRoot,
+};
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
-use sp_core::H256;
 use sp_runtime::{traits::One, Perbill};
 use sp_version::RuntimeVersion;
 
@@ -45,9 +50,9 @@ use pallet_contracts::migration::{v15, v16};
 
 // Local module imports
 use super::{
-	AccountId, Aura, Balance, Balances, Block, Timestamp, BlockNumber, TransactionPayment, Hash, Nonce, PalletInfo, Runtime,
-	RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
-	System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION,
+	AccountId, Aura, Balance, Balances, Block, Timestamp, BlockNumber, TransactionPayment, Hash, Nonce, OriginCaller, PalletInfo,
+	Preimage, RandomnessCollectiveFlip, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask,
+	System, EXISTENTIAL_DEPOSIT, MICRO_UNIT, SLOT_DURATION, UNIT, VERSION,
 };
 
 const NORMAL_DISPATCH_RATIO: Perbill = Perbill::from_percent(75);
@@ -162,34 +167,107 @@ impl pallet_sudo::Config for Runtime {
 	type WeightInfo = pallet_sudo::weights::SubstrateWeight<Runtime>;
 }
 
+impl pallet_utility::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const PreimageBaseDeposit: Balance = UNIT;
+	pub const PreimageByteDeposit: Balance = MICRO_UNIT;
+	pub const PreimageHoldReason: RuntimeHoldReason =
+		RuntimeHoldReason::Preimage(pallet_preimage::HoldReason::Preimage);
+}
+
+impl pallet_preimage::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type WeightInfo = pallet_preimage::weights::SubstrateWeight<Runtime>;
+	type Currency = Balances;
+	type ManagerOrigin = EnsureRoot<AccountId>;
+	type Consideration = HoldConsideration<
+		AccountId,
+		Balances,
+		PreimageHoldReason,
+		LinearStoragePrice<PreimageBaseDeposit, PreimageByteDeposit, Balance>,
+	>;
+}
+
+parameter_types! {
+	/// Scheduled calls may use up to 80% of the block weight.
+	pub MaximumSchedulerWeight: Weight = Perbill::from_percent(80) *
+		RuntimeBlockWeights::get().max_block;
+}
+
+impl pallet_scheduler::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeOrigin = RuntimeOrigin;
+	type PalletsOrigin = OriginCaller;
+	type RuntimeCall = RuntimeCall;
+	type MaximumWeight = MaximumSchedulerWeight;
+	/// Only root (i.e. sudo) may schedule calls for now.
+	type ScheduleOrigin = EnsureRoot<AccountId>;
+	type OriginPrivilegeCmp = EqualPrivilegeOnly;
+	type MaxScheduledPerBlock = ConstU32<50>;
+	type WeightInfo = pallet_scheduler::weights::SubstrateWeight<Runtime>;
+	type Preimages = Preimage;
+	type BlockNumberProvider = System;
+}
+
 parameter_types! {
-	pub const MaxCodeLen: u32 = 128 * 1024;
+	/// Maximum number of nested contract frames below the initial call, so a call chain can be
+	/// `CallStackDepth + 1` contracts deep.
+	///
+	/// Every frame can hold a full contract, so `pallet_contracts`' integrity test bounds
+	/// `MaxCodeLen` by the runtime memory divided by the call depth. Raise this only together
+	/// with a matching decrease of `MaxCodeLen`.
+	pub const CallStackDepth: usize = 8;
+	pub const MaxCodeLen: u32 = 64 * 1024;
 	pub const MaxStorageKeyLen: u32 = 128;
 	pub Schedule: pallet_contracts::Schedule<Runtime> = pallet_contracts::Schedule::default();
 	pub const CodeHashLockupDepositPercent: Perbill = Perbill::from_percent(30);
 
 }
 
-pub struct DummyRandomness;
-impl frame_support::traits::Randomness<H256, BlockNumber> for DummyRandomness {
-    fn random(_subject: &[u8]) -> (H256, BlockNumber) {
-        (Default::default(), 0)
-    }
+/// Randomness for `seal_random`, mixed from the last 81 block hashes.
+///
+/// This is predictable and can be influenced by block authors, so it is only suitable for
+/// development and test chains. Production chains should use a VRF-based source such as
+/// `pallet_babe::RandomnessFromOneEpochAgo` instead.
+impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
+
+/// Runtime calls that contracts may dispatch through `seal_call_runtime`.
+///
+/// This is an allowlist: only plain balance transfers are let through, so privileged calls such
+/// as `Sudo` and `System` stay out of reach of contracts.
+pub struct ContractsCallFilter;
+impl Contains<RuntimeCall> for ContractsCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::Balances(
+				pallet_balances::Call::transfer_allow_death { .. } |
+					pallet_balances::Call::transfer_keep_alive { .. } |
+					pallet_balances::Call::transfer_all { .. }
+			)
+		)
+	}
 }
 
 impl pallet_contracts::Config for Runtime{
 	type Time = Timestamp;
-	type Randomness = DummyRandomness;
+	type Randomness = RandomnessCollectiveFlip;
 	type Currency = Balances;
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
 	type RuntimeHoldReason = RuntimeHoldReason;
-	type CallFilter = Nothing;
+	type CallFilter = ContractsCallFilter;
 	type WeightPrice = TransactionPayment;
 	type WeightInfo = pallet_contracts::weights::SubstrateWeight<Runtime>;
 	type ChainExtension = (); 
 	type Schedule = Schedule;
-	type CallStack = [pallet_contracts::Frame<Self>; 5];
+	type CallStack = [pallet_contracts::Frame<Self>; CallStackDepth::get()];
 
 	type DepositPerByte = DepositPerByte;
 	type DepositPerItem = DepositPerItem;
@@ -221,3 +299,62 @@ impl pallet_template::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::traits::{Hooks, Randomness};
+	use sp_core::H256;
+	use sp_runtime::BuildStorage;
+
+	type ContractsRandomness = <Runtime as pallet_contracts::Config>::Randomness;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap().into()
+	}
+
+	fn run_to_block(n: BlockNumber) {
+		for b in (System::block_number() + 1)..=n {
+			System::initialize(&b, &H256::repeat_byte(b as u8), &Default::default());
+			RandomnessCollectiveFlip::on_initialize(b);
+		}
+	}
+
+	#[test]
+	fn contracts_randomness_varies_per_subject_and_block() {
+		new_test_ext().execute_with(|| {
+			run_to_block(3);
+			let (first, _) = ContractsRandomness::random(b"first");
+			let (second, _) = ContractsRandomness::random(b"second");
+			assert_ne!(first, H256::zero());
+			assert_ne!(first, second);
+
+			run_to_block(4);
+			let (next, _) = ContractsRandomness::random(b"first");
+			assert_ne!(first, next);
+		});
+	}
+
+	#[test]
+	fn contracts_call_filter_allows_only_transfers() {
+		let dest = AccountId::new([1u8; 32]);
+		let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+			dest: dest.clone().into(),
+			value: UNIT,
+		});
+		assert!(ContractsCallFilter::contains(&transfer));
+
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		assert!(!ContractsCallFilter::contains(&remark));
+
+		let sudo = RuntimeCall::Sudo(pallet_sudo::Call::sudo { call: Box::new(transfer) });
+		assert!(!ContractsCallFilter::contains(&sudo));
+
+		let force = RuntimeCall::Balances(pallet_balances::Call::force_transfer {
+			source: dest.clone().into(),
+			dest: dest.into(),
+			value: UNIT,
+		});
+		assert!(!ContractsCallFilter::contains(&force));
+	}
+}
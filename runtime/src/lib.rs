@@ -235,4 +235,16 @@ mod runtime {
 
 	#[runtime::pallet_index(8)]
 	pub type Contracts = pallet_contracts;
+
+	#[runtime::pallet_index(9)]
+	pub type Utility = pallet_utility;
+
+	#[runtime::pallet_index(10)]
+	pub type Preimage = pallet_preimage;
+
+	#[runtime::pallet_index(11)]
+	pub type Scheduler = pallet_scheduler;
+
+	#[runtime::pallet_index(12)]
+	pub type RandomnessCollectiveFlip = pallet_insecure_randomness_collective_flip;
 }